@@ -2,7 +2,52 @@ use ::std::collections::HashMap;
 use ::strum::IntoEnumIterator;
 use ::strum_macros::EnumIter;
 
-use crate::style::presets::ASCII_FULL;
+use crossterm::style::{ResetColor, SetForegroundColor};
+
+use crate::style::modifiers::UTF8_ROUND_CORNERS;
+use crate::style::presets::{ASCII_BORDERS_ONLY, ASCII_FULL, NOTHING, UTF8_FULL};
+use crate::{CellAlignment, Color};
+
+/// The horizontal border line a [border title](TableStyle::set_border_title) can be written into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Border {
+    Top,
+    Bottom,
+}
+
+/// A text label that gets painted into a horizontal [Border] line, e.g. a caption
+/// centered in the top border such as `┌──── Results ────┐`.
+#[derive(Debug, Clone)]
+struct BorderTitle {
+    text: String,
+    alignment: CellAlignment,
+}
+
+/// A position within a column's horizontal border segment, counted either from the
+/// start or the end. Used by [TableStyle::set_border_char_at] to override a single
+/// glyph, e.g. to place Markdown's `:---:` alignment colons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Offset {
+    /// Counted from the start of the segment, `Begin(0)` is the first character.
+    Begin(usize),
+    /// Counted from the end of the segment, `End(0)` is the last character.
+    End(usize),
+}
+
+/// A set of glyphs used to draw a single horizontal or vertical separator line, used
+/// to override the line drawn at a specific row/column boundary via
+/// [TableStyle::set_horizontal_line] / [TableStyle::set_vertical_line].
+///
+/// `line` fills the run, while `left`/`middle`/`right` are used where the line meets
+/// the outer border or crosses other lines, mirroring the left/middle/right
+/// intersection components that already exist for the global style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineStyle {
+    pub line: char,
+    pub left: char,
+    pub middle: char,
+    pub right: char,
+}
 
 pub enum ContentArrangement {
     /// Don't do any automatic width calculation.
@@ -14,10 +59,117 @@ pub enum ContentArrangement {
     /// the best column layout for the given content.
     /// Constraints on columns are still respected.
     Automatic,
-    // /// Same as Automatic, but the full width of the terminal will always be used.
-    // /// Use this, if you want tables to use as much space as possible.
-    // /// Constraints on columns are still respected.
-    // Full,
+    /// Same as Automatic, but the full width of the terminal will always be used.
+    /// Use this, if you want tables to use as much space as possible.
+    /// Constraints on columns are still respected.
+    Full,
+}
+
+/// Distribute any leftover terminal width across `widths` when `arrangement` is
+/// [ContentArrangement::Full]. Does nothing for [ContentArrangement::Disabled] or
+/// [ContentArrangement::Automatic], which stop growing columns once content fits.
+///
+/// `widths` holds each column's width after the normal automatic-arrangement solver
+/// has assigned minimum widths that satisfy content and constraints. `max_widths`
+/// carries each column's optional upper bound coming from its `ColumnConstraint`
+/// (`None` for an unconstrained column). `line_overhead` is the number of characters
+/// the rendered grid spends on borders/separators outside of the columns themselves
+/// (left border + right border + one separator per internal column boundary).
+///
+/// Leftover space (`terminal_width - line_overhead - sum(widths)`) is distributed
+/// proportionally to each column's current width, clamped to that column's max
+/// width. Because proportional shares are rounded down and clamped, this can leave a
+/// remainder; that remainder is pushed onto the last column that still has headroom,
+/// spilling over to earlier columns once that one reaches its max width, so the
+/// rendered table's right edge lines up exactly with the terminal edge unless every
+/// column has reached its max width.
+///
+/// This crate snapshot only contains `src/style/table.rs` — there is no
+/// `Table`/automatic-arrangement module here that measures content and calls into
+/// an arrangement solver, so nothing in this tree invokes this function outside of
+/// its own tests yet. It's written as the complete, standalone integration point
+/// that solver's `Full` branch should call once that module exists: give it the
+/// minimum widths it already computed and this distributes the remaining space.
+pub fn arrange_full_width(
+    arrangement: &ContentArrangement,
+    widths: &mut [usize],
+    max_widths: &[Option<usize>],
+    terminal_width: usize,
+    line_overhead: usize,
+) {
+    if !matches!(arrangement, ContentArrangement::Full) || widths.is_empty() {
+        return;
+    }
+
+    let content_total: usize = widths.iter().sum();
+    let available = terminal_width.saturating_sub(line_overhead);
+    if available <= content_total {
+        return;
+    }
+    let leftover = available - content_total;
+    // Every column's share is proportional to its own width, so a single-column
+    // table (or an all-zero-width table) just gets the entire leftover.
+    let proportional_base = content_total.max(1);
+
+    let mut increases = vec![0usize; widths.len()];
+    for (index, width) in widths.iter().enumerate() {
+        let share = leftover * width / proportional_base;
+        let room = max_widths[index].map_or(usize::MAX, |max| max.saturating_sub(*width));
+        increases[index] = share.min(room);
+    }
+
+    let mut remainder = leftover - increases.iter().sum::<usize>();
+    for index in (0..widths.len()).rev() {
+        if remainder == 0 {
+            break;
+        }
+        let grown = widths[index] + increases[index];
+        let room = max_widths[index].map_or(remainder, |max| max.saturating_sub(grown));
+        let extra = remainder.min(room);
+        increases[index] += extra;
+        remainder -= extra;
+    }
+
+    for (width, increase) in widths.iter_mut().zip(increases) {
+        *width += increase;
+    }
+}
+
+#[cfg(test)]
+mod full_arrangement_tests {
+    use super::*;
+
+    #[test]
+    fn distributes_leftover_proportionally() {
+        let mut widths = vec![4, 8];
+        let max_widths = vec![None, None];
+        arrange_full_width(&ContentArrangement::Full, &mut widths, &max_widths, 30, 3);
+
+        // available = 30 - 3 = 27, leftover = 27 - 12 = 15, split 4:8 -> 5:10
+        assert_eq!(widths, vec![9, 18]);
+    }
+
+    #[test]
+    fn respects_max_width_and_pushes_remainder_onto_last_column() {
+        let mut widths = vec![4, 4, 4];
+        let max_widths = vec![Some(6), None, None];
+        arrange_full_width(&ContentArrangement::Full, &mut widths, &max_widths, 24, 0);
+
+        // leftover = 24 - 12 = 12, proportional share is 4 each; column 0 is capped at
+        // +2 (6 - 4), so its unused +2 share becomes part of the remainder that goes
+        // to the last unconstrained column.
+        assert_eq!(widths[0], 6);
+        assert_eq!(widths[0] + widths[1] + widths[2], 24);
+    }
+
+    #[test]
+    fn automatic_and_disabled_arrangements_are_unaffected() {
+        for arrangement in [ContentArrangement::Automatic, ContentArrangement::Disabled] {
+            let mut widths = vec![4, 8];
+            arrange_full_width(&arrangement, &mut widths, &[None, None], 100, 0);
+            assert_eq!(widths, vec![4, 8]);
+        }
+    }
 }
 
 /// All configurable table components.
@@ -36,7 +188,7 @@ pub enum ContentArrangement {
 /// |  |   |   |
 /// +--+---+---+
 /// ```
-#[derive(Debug, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
 pub enum Component {
     LeftBorder,
     RightBorder,
@@ -64,6 +216,11 @@ pub enum Component {
 pub struct TableStyle {
     pub(crate) has_header: bool,
     style: HashMap<Component, char>,
+    colors: HashMap<Component, Color>,
+    titles: HashMap<Border, BorderTitle>,
+    char_overrides: HashMap<Component, Vec<(Offset, char)>>,
+    horizontal_lines: HashMap<usize, LineStyle>,
+    vertical_lines: HashMap<usize, LineStyle>,
 }
 
 impl TableStyle {
@@ -72,6 +229,11 @@ impl TableStyle {
         let mut table_style = TableStyle {
             has_header: false,
             style: HashMap::new(),
+            colors: HashMap::new(),
+            titles: HashMap::new(),
+            char_overrides: HashMap::new(),
+            horizontal_lines: HashMap::new(),
+            vertical_lines: HashMap::new(),
         };
         table_style.load_preset(ASCII_FULL);
 
@@ -164,11 +326,768 @@ impl TableStyle {
     pub fn style_or_default(&self, component: Component) -> String {
         match self.style.get(&component) {
             None => " ".to_string(),
-            Some(character) => character.to_string(),
+            Some(character) => self.colorize(component, character.to_string()),
         }
     }
 
     pub fn style_exists(&self, component: Component) -> bool {
         self.style.get(&component).is_some()
     }
-}
\ No newline at end of file
+
+    /// Define the color that will be used to draw a specific component.
+    /// Look at [Component] to see all stylable Components.
+    ///
+    /// If `None` is supplied, the component will be drawn in the terminal's default color.
+    pub fn set_color(&mut self, component: Component, color: Option<Color>) -> &mut Self {
+        match color {
+            Some(color) => {
+                self.colors.insert(component, color);
+            }
+            None => {
+                self.colors.remove(&component);
+            }
+        };
+
+        self
+    }
+
+    /// Get a copy of the color currently used for drawing a specific component
+    pub fn get_color(&self, component: Component) -> Option<Color> {
+        self.colors.get(&component).copied()
+    }
+
+    /// Wrap `text` in the ANSI escape sequence for `component`'s color, if one is set.
+    /// Components without a configured color are returned untouched, so plain styles
+    /// continue to produce byte-identical output.
+    fn colorize(&self, component: Component, text: String) -> String {
+        match self.colors.get(&component) {
+            None => text,
+            Some(color) => format!("{}{}{}", SetForegroundColor(*color), text, ResetColor),
+        }
+    }
+
+    /// Write a text label into the given [Border]'s horizontal line, e.g. a caption
+    /// centered in the top border. The title only overwrites already-drawn border
+    /// glyphs at render time, so it never affects column width calculation.
+    ///
+    /// Passing `None` as the title removes it again.
+    pub fn set_border_title(
+        &mut self,
+        border: Border,
+        title: Option<String>,
+        alignment: CellAlignment,
+    ) -> &mut Self {
+        match title {
+            Some(text) => {
+                self.titles.insert(border, BorderTitle { text, alignment });
+            }
+            None => {
+                self.titles.remove(&border);
+            }
+        };
+
+        self
+    }
+
+    /// Get a copy of the title currently configured for the given [Border], if any.
+    pub fn get_border_title(&self, border: Border) -> Option<(String, CellAlignment)> {
+        self.titles
+            .get(&border)
+            .map(|title| (title.text.clone(), title.alignment))
+    }
+
+    /// Paint `title` over `line`, a already-rendered run of horizontal border glyphs,
+    /// at the offset dictated by the title's alignment. Truncates the title with an
+    /// ellipsis if it doesn't fit in `line`'s width.
+    pub(crate) fn overwrite_border_title(&self, border: Border, line: &mut [char]) {
+        let Some(title) = self.titles.get(&border) else {
+            return;
+        };
+
+        let available = line.len();
+        if available == 0 {
+            return;
+        }
+
+        let chars: Vec<char> = if title.text.chars().count() > available {
+            let keep = available.saturating_sub(1);
+            title
+                .text
+                .chars()
+                .take(keep)
+                .chain(std::iter::once('…'))
+                .collect()
+        } else {
+            title.text.chars().collect()
+        };
+
+        let offset = match title.alignment {
+            CellAlignment::Left => 0,
+            CellAlignment::Right => available - chars.len(),
+            CellAlignment::Center => (available - chars.len()) / 2,
+        };
+
+        for (index, character) in chars.into_iter().enumerate() {
+            line[offset + index] = character;
+        }
+    }
+
+    /// Render a single-segment horizontal line for `border`, with that border's
+    /// configured title, if any, painted over the top via
+    /// [overwrite_border_title](Self::overwrite_border_title), then colorized with
+    /// `component`'s color exactly like [style_or_default](Self::style_or_default)
+    /// and [draw_column_segment](Self::draw_column_segment) do. Colorizing the title
+    /// together with the surrounding glyphs, rather than leaving it in the
+    /// terminal's default color, is what makes a colored border with a title
+    /// compose the way [set_color](Self::set_color) promises.
+    ///
+    /// `component` supplies the fill glyph the line is drawn with before the title
+    /// is applied, and `width` is the total number of characters in the line.
+    pub fn draw_border_line(&self, border: Border, component: Component, width: usize) -> String {
+        let mut line: Vec<char> = vec![self.style_char(component); width];
+        self.overwrite_border_title(border, &mut line);
+        self.colorize(component, line.into_iter().collect())
+    }
+
+    /// Render the complete `border` line across every column: each column's span
+    /// drawn with `component`'s fill glyph, separated by that border's intersection
+    /// glyph and capped with its corner glyphs, with the configured title (if any)
+    /// painted over the top and the whole line colorized with `component`'s color.
+    ///
+    /// For a single column (or none), this delegates straight to
+    /// [draw_border_line](Self::draw_border_line) since there's no intersection to
+    /// draw; multiple columns need their boundaries spliced in before the title is
+    /// painted and the line is colorized, so they can't reuse it directly.
+    pub fn render_border(&self, border: Border, column_widths: &[usize]) -> String {
+        let (component, corner_left, intersection, corner_right) = match border {
+            Border::Top => (
+                Component::TopBorder,
+                Component::TopLeftCorner,
+                Component::TopBorderIntersections,
+                Component::TopRightCorner,
+            ),
+            Border::Bottom => (
+                Component::BottomBorder,
+                Component::BottomLeftCorner,
+                Component::BottomBorderIntersections,
+                Component::BottomRightCorner,
+            ),
+        };
+
+        let corner_left = self.style_or_default(corner_left);
+        let corner_right = self.style_or_default(corner_right);
+
+        if column_widths.len() <= 1 {
+            let width = column_widths.first().copied().unwrap_or(0);
+            let line = self.draw_border_line(border, component, width);
+            return format!("{corner_left}{line}{corner_right}");
+        }
+
+        let mut line: Vec<char> = Vec::new();
+        for (index, &width) in column_widths.iter().enumerate() {
+            line.extend(std::iter::repeat_n(self.style_char(component), width));
+            if index + 1 < column_widths.len() {
+                line.push(self.style_char(intersection));
+            }
+        }
+        self.overwrite_border_title(border, &mut line);
+        let line = self.colorize(component, line.into_iter().collect());
+
+        format!("{corner_left}{line}{corner_right}")
+    }
+
+    /// Override a single character within every column's horizontal segment for
+    /// `component`, at `offset` counted from the begin or end of the segment.
+    ///
+    /// This is useful for decorations that the fixed per-component char can't express,
+    /// e.g. Markdown's alignment colons (`|:---:|`), which need the `HeaderLines`
+    /// segment to carry different characters at its ends than in its middle.
+    pub fn set_border_char_at(
+        &mut self,
+        component: Component,
+        offset: Offset,
+        character: char,
+    ) -> &mut Self {
+        let overrides = self.char_overrides.entry(component).or_default();
+        overrides.retain(|(existing_offset, _)| *existing_offset != offset);
+        overrides.push((offset, character));
+
+        self
+    }
+
+    /// Apply any [Offset] overrides registered for `component` to an already-filled
+    /// column segment.
+    pub(crate) fn overwrite_segment_chars(&self, component: Component, segment: &mut [char]) {
+        let Some(overrides) = self.char_overrides.get(&component) else {
+            return;
+        };
+
+        for (offset, character) in overrides {
+            let index = match *offset {
+                Offset::Begin(n) => n,
+                Offset::End(n) => match n.checked_add(1).and_then(|n| segment.len().checked_sub(n)) {
+                    Some(index) => index,
+                    None => continue,
+                },
+            };
+
+            if let Some(slot) = segment.get_mut(index) {
+                *slot = *character;
+            }
+        }
+    }
+
+    /// Render one column's horizontal segment for `component`: a run of `width`
+    /// fill glyphs with any [Offset] overrides registered via
+    /// [set_border_char_at](Self::set_border_char_at) patched in, then colorized.
+    pub fn draw_column_segment(&self, component: Component, width: usize) -> String {
+        let mut segment: Vec<char> = vec![self.style_char(component); width];
+        self.overwrite_segment_chars(component, &mut segment);
+
+        self.colorize(component, segment.into_iter().collect())
+    }
+
+    /// Render the complete divider drawn directly under the header row: one
+    /// [draw_column_segment](Self::draw_column_segment) per entry in
+    /// `column_widths`, so per-column [Offset] overrides on `HeaderLines` (e.g.
+    /// Markdown's `:---:` alignment colons) apply column by column, joined by the
+    /// header's middle intersection glyph and capped with its left/right
+    /// intersection glyphs.
+    pub fn header_divider(&self, column_widths: &[usize]) -> String {
+        if column_widths.is_empty() {
+            return String::new();
+        }
+
+        let segments: Vec<String> = column_widths
+            .iter()
+            .map(|&width| self.draw_column_segment(Component::HeaderLines, width))
+            .collect();
+        let middle = self.style_or_default(Component::MiddleHeaderIntersections);
+        let left = self.style_or_default(Component::LeftHeaderIntersection);
+        let right = self.style_or_default(Component::RightHeaderIntersection);
+
+        format!("{left}{}{right}", segments.join(&middle))
+    }
+
+    /// Register (or, with `None`, clear) an override [LineStyle] for the horizontal
+    /// separator drawn immediately after row `after_row` (0-indexed, counting the
+    /// header as row 0 when present). Falls back to the regular `HeaderLines` /
+    /// `HorizontalLines` component style when no override is set for that boundary.
+    pub fn set_horizontal_line(&mut self, after_row: usize, style: Option<LineStyle>) -> &mut Self {
+        match style {
+            Some(style) => {
+                self.horizontal_lines.insert(after_row, style);
+            }
+            None => {
+                self.horizontal_lines.remove(&after_row);
+            }
+        };
+
+        self
+    }
+
+    /// Get the override [LineStyle] registered for the horizontal separator after
+    /// row `after_row`, if any.
+    pub fn get_horizontal_line(&self, after_row: usize) -> Option<LineStyle> {
+        self.horizontal_lines.get(&after_row).copied()
+    }
+
+    /// Register (or, with `None`, clear) an override [LineStyle] for the vertical
+    /// separator drawn immediately after column `after_column` (0-indexed). Falls
+    /// back to the regular `VerticalLines` component style when no override is set
+    /// for that boundary.
+    pub fn set_vertical_line(&mut self, after_column: usize, style: Option<LineStyle>) -> &mut Self {
+        match style {
+            Some(style) => {
+                self.vertical_lines.insert(after_column, style);
+            }
+            None => {
+                self.vertical_lines.remove(&after_column);
+            }
+        };
+
+        self
+    }
+
+    /// Get the override [LineStyle] registered for the vertical separator after
+    /// column `after_column`, if any.
+    pub fn get_vertical_line(&self, after_column: usize) -> Option<LineStyle> {
+        self.vertical_lines.get(&after_column).copied()
+    }
+
+    /// The `(left_intersection, line, middle_intersection, right_intersection)`
+    /// glyphs to draw the horizontal separator immediately after row `after_row`.
+    ///
+    /// Uses the [LineStyle] registered via [set_horizontal_line](Self::set_horizontal_line)
+    /// for that boundary when one exists, otherwise falls back to the regular
+    /// `HorizontalLines`/`*BorderIntersections`/`MiddleIntersections` component chars,
+    /// or to the `HeaderLines`/`*HeaderIntersection(s)` chars for row 0 of a table
+    /// with a header.
+    pub fn horizontal_line_glyphs(&self, after_row: usize) -> (char, char, char, char) {
+        match self.get_horizontal_line(after_row) {
+            Some(line_style) => (
+                line_style.left,
+                line_style.line,
+                line_style.middle,
+                line_style.right,
+            ),
+            None if self.has_header && after_row == 0 => (
+                self.style_char(Component::LeftHeaderIntersection),
+                self.style_char(Component::HeaderLines),
+                self.style_char(Component::MiddleHeaderIntersections),
+                self.style_char(Component::RightHeaderIntersection),
+            ),
+            None => (
+                self.style_char(Component::LeftBorderIntersections),
+                self.style_char(Component::HorizontalLines),
+                self.style_char(Component::MiddleIntersections),
+                self.style_char(Component::RightBorderIntersections),
+            ),
+        }
+    }
+
+    /// The `(top_intersection, line, middle_intersection, bottom_intersection)`
+    /// glyphs to draw the vertical separator immediately after column `after_column`.
+    ///
+    /// Uses the [LineStyle] registered via [set_vertical_line](Self::set_vertical_line)
+    /// for that boundary when one exists, otherwise falls back to the regular
+    /// `VerticalLines`/`*BorderIntersections`/`MiddleIntersections` component chars.
+    pub fn vertical_line_glyphs(&self, after_column: usize) -> (char, char, char, char) {
+        match self.get_vertical_line(after_column) {
+            Some(line_style) => (
+                line_style.left,
+                line_style.line,
+                line_style.middle,
+                line_style.right,
+            ),
+            None => (
+                self.style_char(Component::TopBorderIntersections),
+                self.style_char(Component::VerticalLines),
+                self.style_char(Component::MiddleIntersections),
+                self.style_char(Component::BottomBorderIntersections),
+            ),
+        }
+    }
+
+    /// The raw, uncolored char currently assigned to `component`, or a space when
+    /// the component isn't drawn.
+    fn style_char(&self, component: Component) -> char {
+        self.style.get(&component).copied().unwrap_or(' ')
+    }
+
+    /// Render the complete divider line drawn between rows, immediately after row
+    /// `after_row`: one run of [horizontal_line_glyphs](Self::horizontal_line_glyphs)'s
+    /// fill glyph per entry in `column_widths`, joined by that boundary's middle
+    /// glyph and capped with its left/right glyphs.
+    pub fn horizontal_divider(&self, after_row: usize, column_widths: &[usize]) -> String {
+        if column_widths.is_empty() {
+            return String::new();
+        }
+
+        let (left, fill, middle, right) = self.horizontal_line_glyphs(after_row);
+        let segments: Vec<String> = column_widths
+            .iter()
+            .map(|&width| std::iter::repeat_n(fill, width).collect())
+            .collect();
+
+        format!("{left}{}{right}", segments.join(&middle.to_string()))
+    }
+
+    /// The glyph a row renderer prints between column `after_column` and the next
+    /// one: the `line` glyph of that boundary's
+    /// [vertical_line_glyphs](Self::vertical_line_glyphs).
+    pub fn vertical_divider(&self, after_column: usize) -> char {
+        self.vertical_line_glyphs(after_column).1
+    }
+
+    /// Build a `TableStyle` from a comma-separated spec string such as
+    /// `"rounded,header,vertical-lines"` or `"borders-only"`.
+    ///
+    /// If the spec contains any named preset keyword (`full`, `plain`, `rounded`,
+    /// `borders-only`), the first one found wins and every other token is ignored.
+    /// Otherwise each token enables one component group (`borders`, `header`,
+    /// `vertical-lines`, `horizontal-lines`, `corners`) on top of an otherwise blank
+    /// style, letting callers compose a style from a single config value without
+    /// knowing the fixed character ordering [load_preset](Self::load_preset) expects.
+    pub fn from_spec(spec: &str) -> Self {
+        let tokens: Vec<&str> = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .collect();
+
+        if let Some(preset_token) = tokens.iter().find(|token| is_named_preset(token)) {
+            let mut table_style = TableStyle::new();
+            match *preset_token {
+                "full" => table_style.load_preset(ASCII_FULL),
+                "plain" => table_style.load_preset(NOTHING),
+                "rounded" => {
+                    table_style.load_preset(UTF8_FULL);
+                    table_style.apply_modifier(UTF8_ROUND_CORNERS);
+                }
+                "borders-only" => table_style.load_preset(ASCII_BORDERS_ONLY),
+                _ => unreachable!("is_named_preset() and this match must stay in sync"),
+            };
+
+            return table_style;
+        }
+
+        // `TableStyle::new()` already loads `ASCII_FULL`, so this is the glyph source
+        // for any component group we enable below.
+        let source = TableStyle::new();
+
+        let mut table_style = TableStyle::new();
+        table_style.load_preset(NOTHING);
+        for token in tokens {
+            for component in component_group(token) {
+                if let Some(character) = source.style.get(component) {
+                    table_style.style.insert(*component, *character);
+                }
+            }
+        }
+
+        table_style
+    }
+}
+
+/// Whether `token` names one of the predefined presets recognized by
+/// [TableStyle::from_spec].
+fn is_named_preset(token: &str) -> bool {
+    matches!(token, "full" | "plain" | "rounded" | "borders-only")
+}
+
+/// The [Component]s toggled on by a single component-group token in
+/// [TableStyle::from_spec].
+fn component_group(name: &str) -> &'static [Component] {
+    match name {
+        "borders" => &[
+            Component::LeftBorder,
+            Component::RightBorder,
+            Component::TopBorder,
+            Component::BottomBorder,
+        ],
+        "header" => &[
+            Component::LeftHeaderIntersection,
+            Component::HeaderLines,
+            Component::MiddleHeaderIntersections,
+            Component::RightHeaderIntersection,
+        ],
+        // Mirrors the components vertical_line_glyphs()/horizontal_line_glyphs()
+        // actually fall back to, so a composed spec like "borders,vertical-lines"
+        // gets real tee/corner glyphs at the border junctions instead of gaps.
+        "vertical-lines" => &[
+            Component::VerticalLines,
+            Component::MiddleIntersections,
+            Component::TopBorderIntersections,
+            Component::BottomBorderIntersections,
+        ],
+        "horizontal-lines" => &[
+            Component::HorizontalLines,
+            Component::MiddleIntersections,
+            Component::LeftBorderIntersections,
+            Component::RightBorderIntersections,
+        ],
+        "corners" => &[
+            Component::TopLeftCorner,
+            Component::TopRightCorner,
+            Component::BottomLeftCorner,
+            Component::BottomRightCorner,
+        ],
+        _ => &[],
+    }
+}
+#[cfg(test)]
+mod color_tests {
+    use super::*;
+
+    #[test]
+    fn style_or_default_emits_an_escape_only_when_a_color_is_set() {
+        let mut style = TableStyle::new();
+        style.set_color(Component::TopBorder, Some(Color::Red));
+
+        let colored = style.style_or_default(Component::TopBorder);
+        assert_ne!(colored, "-");
+        assert!(colored.contains('-'));
+
+        // Untouched components stay byte-identical to the plain preset output.
+        let plain = style.style_or_default(Component::LeftBorder);
+        assert_eq!(plain, "|");
+    }
+
+    #[test]
+    fn clearing_a_color_restores_plain_output() {
+        let mut style = TableStyle::new();
+        style.set_color(Component::TopBorder, Some(Color::Red));
+        style.set_color(Component::TopBorder, None);
+
+        assert_eq!(style.style_or_default(Component::TopBorder), "-");
+        assert_eq!(style.get_color(Component::TopBorder), None);
+    }
+}
+
+#[cfg(test)]
+mod border_title_tests {
+    use super::*;
+
+    #[test]
+    fn title_is_painted_at_the_requested_alignment() {
+        let mut style = TableStyle::new();
+        style.set_border_title(Border::Top, Some("Hi".to_string()), CellAlignment::Left);
+        assert_eq!(
+            style.draw_border_line(Border::Top, Component::TopBorder, 6),
+            "Hi----"
+        );
+
+        style.set_border_title(Border::Top, Some("Hi".to_string()), CellAlignment::Right);
+        assert_eq!(
+            style.draw_border_line(Border::Top, Component::TopBorder, 6),
+            "----Hi"
+        );
+
+        style.set_border_title(Border::Top, Some("Hi".to_string()), CellAlignment::Center);
+        assert_eq!(
+            style.draw_border_line(Border::Top, Component::TopBorder, 6),
+            "--Hi--"
+        );
+    }
+
+    #[test]
+    fn title_truncates_with_an_ellipsis_when_too_wide() {
+        let mut style = TableStyle::new();
+        style.set_border_title(Border::Top, Some("Results".to_string()), CellAlignment::Left);
+        assert_eq!(
+            style.draw_border_line(Border::Top, Component::TopBorder, 4),
+            "Res…"
+        );
+    }
+
+    #[test]
+    fn border_without_a_title_is_left_untouched() {
+        let mut style = TableStyle::new();
+        style.set_border_title(Border::Top, Some("Hi".to_string()), CellAlignment::Left);
+
+        // Bottom never had a title set, even though Top did.
+        assert_eq!(
+            style.draw_border_line(Border::Bottom, Component::BottomBorder, 5),
+            "-----"
+        );
+
+        style.set_border_title(Border::Top, None, CellAlignment::Left);
+        assert_eq!(
+            style.draw_border_line(Border::Top, Component::TopBorder, 5),
+            "-----"
+        );
+    }
+
+    #[test]
+    fn a_colored_border_colorizes_its_title_too_instead_of_leaving_it_plain() {
+        let mut style = TableStyle::new();
+        style.set_color(Component::TopBorder, Some(Color::Red));
+        style.set_border_title(Border::Top, Some("Hi".to_string()), CellAlignment::Left);
+
+        let line = style.draw_border_line(Border::Top, Component::TopBorder, 6);
+        // The whole line -- title included -- carries exactly one escape/reset
+        // pair, rather than the glyphs being colored and the title left plain.
+        assert_eq!(line.matches('\u{1b}').count(), 2);
+        assert!(line.contains("Hi"));
+    }
+
+    #[test]
+    fn render_border_joins_columns_with_intersections_and_corners() {
+        let style = TableStyle::new();
+        assert_eq!(style.render_border(Border::Top, &[2, 3]), "+--+---+");
+    }
+
+    #[test]
+    fn render_border_paints_the_title_across_the_whole_line() {
+        let mut style = TableStyle::new();
+        style.set_border_title(Border::Top, Some("Hi".to_string()), CellAlignment::Center);
+        assert_eq!(style.render_border(Border::Top, &[2, 3]), "+--Hi--+");
+    }
+}
+
+#[cfg(test)]
+mod border_char_override_tests {
+    use super::*;
+
+    #[test]
+    fn patches_the_requested_offsets() {
+        let mut style = TableStyle::new();
+        style.set_border_char_at(Component::HeaderLines, Offset::Begin(0), ':');
+        style.set_border_char_at(Component::HeaderLines, Offset::End(0), ':');
+
+        assert_eq!(style.draw_column_segment(Component::HeaderLines, 5), ":---:");
+    }
+
+    #[test]
+    fn a_later_override_at_the_same_offset_replaces_the_earlier_one() {
+        let mut style = TableStyle::new();
+        style.set_border_char_at(Component::HeaderLines, Offset::Begin(0), ':');
+        style.set_border_char_at(Component::HeaderLines, Offset::Begin(0), '^');
+
+        assert_eq!(style.draw_column_segment(Component::HeaderLines, 3), "^--");
+    }
+
+    #[test]
+    fn components_without_overrides_are_left_untouched() {
+        let style = TableStyle::new();
+        assert_eq!(style.draw_column_segment(Component::HeaderLines, 4), "----");
+    }
+
+    #[test]
+    fn an_end_offset_past_the_segment_is_ignored_instead_of_overflowing() {
+        let mut style = TableStyle::new();
+        style.set_border_char_at(Component::HeaderLines, Offset::End(usize::MAX), ':');
+
+        // Would panic on overflow in debug builds if `End(n)` computed `n + 1`
+        // directly instead of checking for overflow first.
+        assert_eq!(style.draw_column_segment(Component::HeaderLines, 4), "----");
+    }
+
+    #[test]
+    fn header_divider_joins_column_segments_with_intersections() {
+        let mut style = TableStyle::new();
+        style.set_border_char_at(Component::HeaderLines, Offset::Begin(0), ':');
+        style.set_border_char_at(Component::HeaderLines, Offset::End(0), ':');
+
+        assert_eq!(style.header_divider(&[3, 4]), "+:-:+:--:+");
+    }
+}
+
+#[cfg(test)]
+mod boundary_line_style_tests {
+    use super::*;
+
+    #[test]
+    fn horizontal_line_glyphs_fall_back_without_an_override() {
+        let style = TableStyle::new();
+        assert_eq!(style.horizontal_line_glyphs(0), ('+', '-', '+', '+'));
+    }
+
+    #[test]
+    fn horizontal_line_glyphs_use_the_override_for_its_boundary_only() {
+        let mut style = TableStyle::new();
+        let double_rule = LineStyle {
+            line: '=',
+            left: '#',
+            middle: '#',
+            right: '#',
+        };
+        style.set_horizontal_line(1, Some(double_rule));
+
+        assert_eq!(style.horizontal_line_glyphs(1), ('#', '=', '#', '#'));
+        assert_eq!(style.horizontal_line_glyphs(0), ('+', '-', '+', '+'));
+    }
+
+    #[test]
+    fn vertical_line_glyphs_fall_back_without_an_override() {
+        let style = TableStyle::new();
+        assert_eq!(style.vertical_line_glyphs(0).1, '|');
+    }
+
+    #[test]
+    fn vertical_line_glyphs_use_the_override_for_its_boundary_only() {
+        let mut style = TableStyle::new();
+        let heavy_rule = LineStyle {
+            line: '#',
+            left: '#',
+            middle: '#',
+            right: '#',
+        };
+        style.set_vertical_line(2, Some(heavy_rule));
+
+        assert_eq!(style.vertical_line_glyphs(2).1, '#');
+        assert_eq!(style.vertical_line_glyphs(0).1, '|');
+    }
+
+    #[test]
+    fn clearing_an_override_restores_the_fallback() {
+        let mut style = TableStyle::new();
+        style.set_horizontal_line(0, Some(LineStyle { line: '=', left: '#', middle: '#', right: '#' }));
+        style.set_horizontal_line(0, None);
+
+        assert_eq!(style.horizontal_line_glyphs(0), ('+', '-', '+', '+'));
+    }
+
+    #[test]
+    fn horizontal_divider_joins_column_runs_with_the_boundarys_glyphs() {
+        let style = TableStyle::new();
+        assert_eq!(style.horizontal_divider(0, &[2, 3]), "+--+---+");
+    }
+
+    #[test]
+    fn horizontal_divider_uses_the_override_for_its_own_boundary_only() {
+        let mut style = TableStyle::new();
+        style.set_horizontal_line(
+            1,
+            Some(LineStyle { line: '=', left: '#', middle: '#', right: '#' }),
+        );
+
+        assert_eq!(style.horizontal_divider(1, &[2, 3]), "#==#===#");
+        assert_eq!(style.horizontal_divider(0, &[2, 3]), "+--+---+");
+    }
+
+    #[test]
+    fn vertical_divider_uses_the_override_for_its_own_boundary_only() {
+        let mut style = TableStyle::new();
+        style.set_vertical_line(2, Some(LineStyle { line: '#', left: '#', middle: '#', right: '#' }));
+
+        assert_eq!(style.vertical_divider(2), '#');
+        assert_eq!(style.vertical_divider(0), '|');
+    }
+}
+
+#[cfg(test)]
+mod from_spec_tests {
+    use super::*;
+
+    #[test]
+    fn preset_keyword_wins_over_component_tokens() {
+        let style = TableStyle::from_spec("header,plain,vertical-lines");
+        // `plain` (NOTHING) wins, so none of the usual border chars are present.
+        assert!(!style.style_exists(Component::TopBorder));
+        assert!(!style.style_exists(Component::VerticalLines));
+    }
+
+    #[test]
+    fn first_preset_keyword_in_list_order_wins() {
+        let full = TableStyle::from_spec("full,plain");
+        assert!(full.style_exists(Component::TopBorder));
+
+        let plain = TableStyle::from_spec("plain,full");
+        assert!(!plain.style_exists(Component::TopBorder));
+    }
+
+    #[test]
+    fn without_a_preset_only_the_requested_groups_are_enabled() {
+        let style = TableStyle::from_spec("vertical-lines, header");
+
+        assert!(style.style_exists(Component::VerticalLines));
+        assert!(style.style_exists(Component::HeaderLines));
+        assert!(!style.style_exists(Component::TopBorder));
+        assert!(!style.style_exists(Component::HorizontalLines));
+    }
+
+    #[test]
+    fn unknown_tokens_are_silently_dropped() {
+        let style = TableStyle::from_spec("not-a-real-token");
+        assert!(!style.style_exists(Component::TopBorder));
+        assert!(!style.style_exists(Component::VerticalLines));
+    }
+
+    #[test]
+    fn borders_and_horizontal_lines_compose_with_proper_junctions_at_the_border() {
+        let style = TableStyle::from_spec("borders,horizontal-lines");
+        // Without LeftBorderIntersections/RightBorderIntersections in the
+        // "horizontal-lines" group, this boundary would fall back to blank gaps.
+        assert_eq!(style.horizontal_line_glyphs(0), ('+', '-', '+', '+'));
+    }
+
+    #[test]
+    fn borders_and_vertical_lines_compose_with_proper_junctions_at_the_border() {
+        let style = TableStyle::from_spec("borders,vertical-lines");
+        // Without TopBorderIntersections/BottomBorderIntersections in the
+        // "vertical-lines" group, this boundary would fall back to blank gaps.
+        assert_eq!(style.vertical_line_glyphs(0), ('+', '|', '+', '+'));
+    }
+}